@@ -0,0 +1,60 @@
+//! Typed constructors for the standard `"GNU"` note namespace
+//!
+//! A note's `kind` is only meaningful relative to its `name`, so hand-picking
+//! the raw integers for the well-known GNU notes is error-prone. The
+//! functions here pin down both the name and the `kind` for you.
+
+use crate::{Note, Note8};
+
+/// `kind` of the GNU ABI tag note; see [`abi_tag`]
+pub const NT_GNU_ABI_TAG: u32 = 1;
+
+/// `kind` of the GNU build-ID note; see [`build_id`]
+pub const NT_GNU_BUILD_ID: u32 = 3;
+
+/// `kind` of the GNU property note; see [`property`]
+pub const NT_GNU_PROPERTY_TYPE_0: u32 = 5;
+
+/// The `os` value for [`abi_tag`] identifying the Linux kernel
+pub const ELF_NOTE_OS_LINUX: u32 = 0;
+
+/// The `os` value for [`abi_tag`] identifying the GNU Hurd kernel
+pub const ELF_NOTE_OS_GNU: u32 = 1;
+
+/// The `os` value for [`abi_tag`] identifying Solaris 2
+pub const ELF_NOTE_OS_SOLARIS2: u32 = 2;
+
+/// The `os` value for [`abi_tag`] identifying FreeBSD
+pub const ELF_NOTE_OS_FREEBSD: u32 = 3;
+
+/// Creates a `NT_GNU_BUILD_ID` note (name `"GNU"`) from a build-ID digest
+///
+/// `bytes` is typically a 20-byte SHA-1 hash or a 16-byte UUID, but any
+/// digest length accepted by the linker's `--build-id` is fine here.
+pub const fn build_id<const M: usize>(bytes: [u8; M]) -> Note<[u8; M], 4> {
+    Note::new("GNU", NT_GNU_BUILD_ID, bytes)
+}
+
+/// Creates a `NT_GNU_ABI_TAG` note (name `"GNU"`) declaring the minimum
+/// kernel ABI a binary requires
+///
+/// `os` should be one of the `ELF_NOTE_OS_*` constants in this module.
+pub const fn abi_tag(os: u32, major: u32, minor: u32, patch: u32) -> Note<[u32; 4], 4> {
+    Note::new("GNU", NT_GNU_ABI_TAG, [os, major, minor, patch])
+}
+
+/// Creates a `NT_GNU_PROPERTY_TYPE_0` note (name `"GNU"`) from a
+/// pre-encoded sequence of property records
+///
+/// Each property record is `pr_type` (`u32`), `pr_datasz` (`u32`), then
+/// `pr_datasz` bytes of data padded to a pointer-sized boundary; this
+/// function does not encode that sequence for you, only wraps it in the
+/// note. Unlike [`build_id`] and [`abi_tag`], the desc is aligned to 8
+/// bytes, as required on 64-bit targets — read it back with
+/// [`crate::Notes::new_aligned8`], not [`crate::Notes::new`]. The `"GNU"`
+/// name puts this note's own `desc` at offset 16 either way, but
+/// [`crate::Notes::new`] can read the notes that follow it from the wrong
+/// offset.
+pub const fn property<const M: usize>(bytes: [u8; M]) -> Note8<[u8; M], 4> {
+    Note8::new("GNU", NT_GNU_PROPERTY_TYPE_0, bytes)
+}
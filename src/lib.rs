@@ -6,66 +6,341 @@
 #![deny(missing_docs)]
 #![allow(clippy::needless_doctest_main)]
 
+pub mod gnu;
+
 #[repr(C, packed(4))]
 struct Packed<T>(T);
 
 #[repr(C, align(4))]
-struct Aligned<T>(T);
+struct Aligned4<T>(T);
 
-/// A note as defined in the ELF specification
-///
-/// You probably don't want this struct. The `noted!` macro should provide
-/// everything you need.
+#[repr(C, align(8))]
+struct Aligned8<T>(T);
+
+/// The byte order of a note's `namesz`, `descsz`, and `kind` header fields
+#[derive(Clone, Copy)]
+enum Order {
+    Native,
+    Little,
+    Big,
+}
+
+impl Order {
+    const fn encode(self, v: u32) -> [u8; 4] {
+        match self {
+            Order::Native => v.to_ne_bytes(),
+            Order::Little => v.to_le_bytes(),
+            Order::Big => v.to_be_bytes(),
+        }
+    }
+}
+
+/// Defines a `Note`-shaped struct whose `desc` is padded to `$align` bytes,
+/// along with its byte-order-aware constructors.
 ///
-/// An instance of this struct should be binary compatible with notes as
-/// defined in the ELF specification. However, you MUST put this note in
-/// an appropriate ELF section. For example, `#[link_section = ".note"]`.
-#[repr(C, align(4))]
-pub struct Note<T, const N: usize> {
-    namesz: u32,
-    descsz: u32,
-    kind: u32,
-    name: [u8; N],
-    desc: Aligned<Packed<T>>,
+/// The three constructors only differ in which [`Order`] they bake into the
+/// header fields; the layout is otherwise identical regardless of host
+/// byte order.
+macro_rules! note_type {
+    ($(#[$attr:meta])* $note:ident, $aligned:ident, $align:tt) => {
+        $(#[$attr])*
+        #[repr(C, align($align))]
+        pub struct $note<T, const N: usize> {
+            namesz: [u8; 4],
+            descsz: [u8; 4],
+            kind: [u8; 4],
+            name: [u8; N],
+            desc: $aligned<Packed<T>>,
+        }
+
+        impl<T, const N: usize> $note<T, N> {
+            const fn with_order(name: &'static str, id: u32, desc: T, order: Order) -> Self {
+                assert!(
+                    name.len() < N,
+                    "note name (plus its NUL terminator) does not fit in N; use the `noted!` macro to size it correctly"
+                );
+
+                let mut buf = [0u8; N];
+
+                let mut i = 0;
+                while i < name.len() {
+                    buf[i] = name.as_bytes()[i];
+                    i += 1;
+                }
+
+                $note {
+                    namesz: order.encode(N as u32),
+                    descsz: order.encode(core::mem::size_of::<T>() as u32),
+                    kind: order.encode(id),
+                    name: buf,
+                    desc: $aligned(Packed(desc)),
+                }
+            }
+
+            /// Creates a new instance using the host's native byte order.
+            ///
+            /// You probably don't want this function. The `noted!` macro
+            /// should provide everything you need.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `name` plus its NUL terminator doesn't fit in `N`.
+            /// `name` is an ordinary function parameter rather than a const
+            /// generic, so this is a genuine build error only when the call
+            /// itself is const-evaluated — which every `static`/`const` built
+            /// by the `noted!` macro (or written by hand) already is. Calling
+            /// this function from a non-const expression instead defers the
+            /// same check to run time, the same way any other `const fn`'s
+            /// `assert!` would.
+            pub const fn new(name: &'static str, id: u32, desc: T) -> Self {
+                Self::with_order(name, id, desc, Order::Native)
+            }
+
+            /// Creates a new instance with little-endian header fields,
+            /// regardless of the host's own byte order.
+            ///
+            /// Use this when targeting a little-endian ELF image from a
+            /// big-endian (or unknown-endian) host.
+            ///
+            /// See [`new`](Self::new) for the panic condition on `name`/`N`.
+            pub const fn new_le(name: &'static str, id: u32, desc: T) -> Self {
+                Self::with_order(name, id, desc, Order::Little)
+            }
+
+            /// Creates a new instance with big-endian header fields,
+            /// regardless of the host's own byte order.
+            ///
+            /// Use this when targeting a big-endian ELF image from a
+            /// little-endian (or unknown-endian) host.
+            ///
+            /// See [`new`](Self::new) for the panic condition on `name`/`N`.
+            pub const fn new_be(name: &'static str, id: u32, desc: T) -> Self {
+                Self::with_order(name, id, desc, Order::Big)
+            }
+        }
+    };
 }
 
-impl<T, const N: usize> Note<T, N> {
-    /// Creates a new `Note` instance.
+note_type!(
+    /// A note as defined in the ELF specification, with `desc` aligned to
+    /// 4 bytes.
     ///
-    /// You probably don't want this function. The `noted!` macro should
-    /// provide everything you need.
+    /// An instance of this struct should be binary compatible with notes as
+    /// defined in the ELF specification. However, you MUST put this note in
+    /// an appropriate ELF section. For example, `#[link_section = ".note"]`.
+    Note,
+    Aligned4,
+    4
+);
+
+note_type!(
+    /// A note as defined in the ELF specification, with `desc` aligned to
+    /// 8 bytes, as required by some 64-bit ABIs.
     ///
-    /// Note that if insufficient name bytes (i.e. `N`) are provided, the
-    /// name will be silently truncated. You should use the provided macro
-    /// (see above) to avoid this problem.
-    pub const fn new(name: &'static str, id: u32, desc: T) -> Self {
-        let mut buf = [0u8; N];
-
-        let mut i = 0;
-        while i < N - 1 {
-            buf[i] = name.as_bytes()[i];
-            i += 1;
+    /// An instance of this struct should be binary compatible with notes as
+    /// defined in the ELF specification. However, you MUST put this note in
+    /// an appropriate ELF section. For example, `#[link_section = ".note"]`.
+    Note8,
+    Aligned8,
+    8
+);
+
+/// A single parsed entry from a `PT_NOTE` segment
+///
+/// All fields borrow directly from the buffer the [`Notes`] iterator was
+/// constructed with; nothing is copied or allocated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Entry<'a> {
+    /// The note's originator name (e.g. `"GNU"`), with the trailing NUL
+    /// stripped.
+    pub name: &'a str,
+    /// The note's type, meaningful only within the namespace of `name`.
+    pub kind: u32,
+    /// The note's descriptor bytes.
+    pub desc: &'a [u8],
+}
+
+/// Rounds `n` up to the next multiple of `align`, or `None` on overflow.
+const fn align_up(n: usize, align: usize) -> Option<usize> {
+    match n.checked_add(align - 1) {
+        Some(rounded) => Some(rounded & !(align - 1)),
+        None => None,
+    }
+}
+
+/// An iterator over the notes in a `PT_NOTE` (or `.note*`) segment
+///
+/// Parses the standard ELF note layout: a `namesz`, `descsz`, and `kind`
+/// `u32` (in that order, native byte order), followed by `namesz` bytes of
+/// name and `descsz` bytes of desc, each individually padded to a boundary
+/// fixed when the iterator is constructed — 4 bytes for [`Notes::new`], 8
+/// bytes for [`Notes::new_aligned8`].
+///
+/// Iteration stops, yielding `None`, once fewer than 12 bytes remain or a
+/// note's name or desc, including the padding after its desc, would run
+/// past the end of the buffer. Malformed
+/// trailing data is therefore silently ignored rather than causing a panic.
+///
+/// This iterator assumes the buffer's header fields are in the host's
+/// native byte order. Notes built with [`Note::new_le`]/[`Note::new_be`]
+/// (or their [`Note8`] equivalents) for a foreign-endian target will not
+/// round-trip through this iterator on a host of the opposite endianness;
+/// decoding those requires swapping `namesz`/`descsz`/`kind` yourself.
+///
+/// Picking the wrong alignment doesn't fail loudly: wherever the 4- and
+/// 8-byte paddings differ, it reads `desc` (or the next note) from the
+/// wrong offset, since the padding is part of the layout, not something
+/// recorded in the note itself. A buffer of [`Note8`]s (such as
+/// [`gnu::property`] notes) fed to [`Notes::new`] can silently yield wrong
+/// `desc` bytes or lose the notes that follow rather than an error, so
+/// match the constructor to however the notes in `buf` were actually built.
+///
+/// ```
+/// use noted::{Note, Note8, Notes};
+///
+/// static FOUR: Note<u32, 4> = Note::new("AAA", 7, 0x11223344u32);
+/// let bytes = unsafe {
+///     core::slice::from_raw_parts(
+///         (&FOUR as *const Note<u32, 4>).cast::<u8>(),
+///         core::mem::size_of::<Note<u32, 4>>(),
+///     )
+/// };
+/// let entry = Notes::new(bytes).next().unwrap();
+/// assert_eq!(entry.name, "AAA");
+/// assert_eq!(entry.kind, 7);
+/// assert_eq!(entry.desc, 0x11223344u32.to_ne_bytes());
+///
+/// static EIGHT: Note8<u64, 4> = Note8::new("BBB", 9, 0x1122334455667788u64);
+/// let bytes = unsafe {
+///     core::slice::from_raw_parts(
+///         (&EIGHT as *const Note8<u64, 4>).cast::<u8>(),
+///         core::mem::size_of::<Note8<u64, 4>>(),
+///     )
+/// };
+/// let entry = Notes::new_aligned8(bytes).next().unwrap();
+/// assert_eq!(entry.name, "BBB");
+/// assert_eq!(entry.kind, 9);
+/// assert_eq!(entry.desc, 0x1122334455667788u64.to_ne_bytes());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Notes<'a> {
+    buf: &'a [u8],
+    align: usize,
+}
+
+impl<'a> Notes<'a> {
+    /// Creates a new iterator over the notes in `buf`, assuming `desc` is
+    /// padded to 4 bytes — the layout produced by [`Note`] and the
+    /// `noted!` macro's default (or explicit `align = 4;`).
+    ///
+    /// Use [`Notes::new_aligned8`] instead for a buffer of [`Note8`]s.
+    pub const fn new(buf: &'a [u8]) -> Self {
+        Notes { buf, align: 4 }
+    }
+
+    /// Creates a new iterator over the notes in `buf`, assuming `desc` is
+    /// padded to 8 bytes — the layout produced by [`Note8`], the `noted!`
+    /// macro's `align = 8;`, and [`gnu::property`].
+    pub const fn new_aligned8(buf: &'a [u8]) -> Self {
+        Notes { buf, align: 8 }
+    }
+}
+
+impl<'a> Iterator for Notes<'a> {
+    type Item = Entry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        fn u32_at(buf: &[u8], off: usize) -> Option<u32> {
+            let bytes = buf.get(off..off + 4)?;
+            Some(u32::from_ne_bytes(bytes.try_into().unwrap()))
         }
 
-        Note {
-            namesz: N as u32,
-            descsz: core::mem::size_of::<T>() as u32,
-            kind: id,
-            name: buf,
-            desc: Aligned(Packed(desc)),
+        if self.buf.len() < 12 {
+            return None;
         }
+
+        let namesz = u32_at(self.buf, 0)? as usize;
+        let descsz = u32_at(self.buf, 4)? as usize;
+        let kind = u32_at(self.buf, 8)?;
+
+        let name_start: usize = 12;
+        let name_end = name_start.checked_add(namesz)?;
+        let desc_start = align_up(name_end, self.align)?;
+        let desc_end = desc_start.checked_add(descsz)?;
+        let next = align_up(desc_end, self.align)?;
+
+        if next > self.buf.len() {
+            return None;
+        }
+
+        let name = self.buf.get(name_start..name_end)?;
+        let name = core::str::from_utf8(name).ok()?.trim_end_matches('\0');
+        let desc = self.buf.get(desc_start..desc_end)?;
+
+        self.buf = &self.buf[next..];
+        Some(Entry { name, kind, desc })
     }
 }
 
+/// A linker-script snippet that gathers the per-originator `.note.*`
+/// sections produced by `noted!`'s `section_per_name` mode into a single
+/// `PT_NOTE` output section, the way the Linux kernel's `NOTES` macro
+/// (`include/asm-generic/vmlinux.lds.h`) gathers `vmlinux`'s own notes.
+///
+/// Splice this into your linker script's `SECTIONS` block (and add a
+/// matching `note PT_NOTE FLAGS(4);` entry to its `PHDRS`) so that every
+/// `.note.NAME` section, regardless of which originator emitted it, ends
+/// up packed adjacently in the same `PT_NOTE` segment.
+pub const NOTE_SECTIONS_LINKER_SCRIPT: &str = r#"
+.notes : ALIGN(4)
+{
+    __start_notes = .;
+    *(.note.*)
+    __stop_notes = .;
+} :note
+"#;
+
 /// A macro for creating ELF notes
 ///
+/// By default notes are generated with native byte order and `desc` aligned
+/// to 4 bytes, all in a single `.note` section. An optional `section = ...;`
+/// prefix picks a different section, `align = 4;`/`align = 8;` picks the
+/// `desc` alignment, and `endian = native;`/`endian = le;`/`endian = be;`
+/// picks the byte order of the header fields, for cross-compiling notes
+/// destined for a foreign-endian or 64-bit ELF image.
+///
+/// A `section_per_name;` prefix (in place of `section = ...;`) instead
+/// derives each note's section from its own name, as `.note.NAME`, so that
+/// distinct originators land in distinct sections the way the elfnote
+/// convention expects. Pair it with [`NOTE_SECTIONS_LINKER_SCRIPT`] to
+/// gather them back into one `PT_NOTE` segment. In this mode the name must
+/// be a string literal, since it is spliced into the section name at
+/// compile time.
+///
 /// See the module documentation for an example.
+///
+/// `section_per_name;` composes with `align = ...;` and `endian = ...;` the
+/// same way `section = ...;` does:
+///
+/// ```
+/// use noted::noted;
+///
+/// noted! {
+///     section_per_name; align = 8; endian = be;
+///     static EXAMPLE<"demo", 1u32, u32> = 0;
+/// }
+/// ```
 #[macro_export]
 macro_rules! noted {
-    (@internal $section:literal) => {};
+    (@internal $section:literal, 4, native) => {};
+    (@internal $section:literal, 4, le) => {};
+    (@internal $section:literal, 4, be) => {};
+    (@internal $section:literal, 8, native) => {};
+    (@internal $section:literal, 8, le) => {};
+    (@internal $section:literal, 8, be) => {};
 
     (
-        @internal $section:literal
+        @internal $section:literal, 4, native
 
         $(#[$attr:meta])*
         $vis:vis static $symb:ident<$name:expr, $type:expr, $kind:ty> = $desc:expr;
@@ -77,14 +352,229 @@ macro_rules! noted {
         #[used]
         $vis static $symb: $crate::Note<$kind, {$name.len() + 1}> = $crate::Note::new($name, $type, $desc);
 
-        noted! { @internal $section $($next)* }
+        noted! { @internal $section, 4, native $($next)* }
+    };
+
+    (
+        @internal $section:literal, 4, le
+
+        $(#[$attr:meta])*
+        $vis:vis static $symb:ident<$name:expr, $type:expr, $kind:ty> = $desc:expr;
+
+        $($next:tt)*
+    ) => {
+        #[link_section = $section]
+        $(#[$attr])*
+        #[used]
+        $vis static $symb: $crate::Note<$kind, {$name.len() + 1}> = $crate::Note::new_le($name, $type, $desc);
+
+        noted! { @internal $section, 4, le $($next)* }
     };
 
+    (
+        @internal $section:literal, 4, be
+
+        $(#[$attr:meta])*
+        $vis:vis static $symb:ident<$name:expr, $type:expr, $kind:ty> = $desc:expr;
+
+        $($next:tt)*
+    ) => {
+        #[link_section = $section]
+        $(#[$attr])*
+        #[used]
+        $vis static $symb: $crate::Note<$kind, {$name.len() + 1}> = $crate::Note::new_be($name, $type, $desc);
+
+        noted! { @internal $section, 4, be $($next)* }
+    };
+
+    (
+        @internal $section:literal, 8, native
+
+        $(#[$attr:meta])*
+        $vis:vis static $symb:ident<$name:expr, $type:expr, $kind:ty> = $desc:expr;
+
+        $($next:tt)*
+    ) => {
+        #[link_section = $section]
+        $(#[$attr])*
+        #[used]
+        $vis static $symb: $crate::Note8<$kind, {$name.len() + 1}> = $crate::Note8::new($name, $type, $desc);
+
+        noted! { @internal $section, 8, native $($next)* }
+    };
+
+    (
+        @internal $section:literal, 8, le
+
+        $(#[$attr:meta])*
+        $vis:vis static $symb:ident<$name:expr, $type:expr, $kind:ty> = $desc:expr;
+
+        $($next:tt)*
+    ) => {
+        #[link_section = $section]
+        $(#[$attr])*
+        #[used]
+        $vis static $symb: $crate::Note8<$kind, {$name.len() + 1}> = $crate::Note8::new_le($name, $type, $desc);
+
+        noted! { @internal $section, 8, le $($next)* }
+    };
+
+    (
+        @internal $section:literal, 8, be
+
+        $(#[$attr:meta])*
+        $vis:vis static $symb:ident<$name:expr, $type:expr, $kind:ty> = $desc:expr;
+
+        $($next:tt)*
+    ) => {
+        #[link_section = $section]
+        $(#[$attr])*
+        #[used]
+        $vis static $symb: $crate::Note8<$kind, {$name.len() + 1}> = $crate::Note8::new_be($name, $type, $desc);
+
+        noted! { @internal $section, 8, be $($next)* }
+    };
+
+    (@internal_per_name 4, native) => {};
+    (@internal_per_name 4, le) => {};
+    (@internal_per_name 4, be) => {};
+    (@internal_per_name 8, native) => {};
+    (@internal_per_name 8, le) => {};
+    (@internal_per_name 8, be) => {};
+
+    (
+        @internal_per_name 4, native
+
+        $(#[$attr:meta])*
+        $vis:vis static $symb:ident<$name:literal, $type:expr, $kind:ty> = $desc:expr;
+
+        $($next:tt)*
+    ) => {
+        #[link_section = concat!(".note.", $name)]
+        $(#[$attr])*
+        #[used]
+        $vis static $symb: $crate::Note<$kind, {$name.len() + 1}> = $crate::Note::new($name, $type, $desc);
+
+        noted! { @internal_per_name 4, native $($next)* }
+    };
+
+    (
+        @internal_per_name 4, le
+
+        $(#[$attr:meta])*
+        $vis:vis static $symb:ident<$name:literal, $type:expr, $kind:ty> = $desc:expr;
+
+        $($next:tt)*
+    ) => {
+        #[link_section = concat!(".note.", $name)]
+        $(#[$attr])*
+        #[used]
+        $vis static $symb: $crate::Note<$kind, {$name.len() + 1}> = $crate::Note::new_le($name, $type, $desc);
+
+        noted! { @internal_per_name 4, le $($next)* }
+    };
+
+    (
+        @internal_per_name 4, be
+
+        $(#[$attr:meta])*
+        $vis:vis static $symb:ident<$name:literal, $type:expr, $kind:ty> = $desc:expr;
+
+        $($next:tt)*
+    ) => {
+        #[link_section = concat!(".note.", $name)]
+        $(#[$attr])*
+        #[used]
+        $vis static $symb: $crate::Note<$kind, {$name.len() + 1}> = $crate::Note::new_be($name, $type, $desc);
+
+        noted! { @internal_per_name 4, be $($next)* }
+    };
+
+    (
+        @internal_per_name 8, native
+
+        $(#[$attr:meta])*
+        $vis:vis static $symb:ident<$name:literal, $type:expr, $kind:ty> = $desc:expr;
+
+        $($next:tt)*
+    ) => {
+        #[link_section = concat!(".note.", $name)]
+        $(#[$attr])*
+        #[used]
+        $vis static $symb: $crate::Note8<$kind, {$name.len() + 1}> = $crate::Note8::new($name, $type, $desc);
+
+        noted! { @internal_per_name 8, native $($next)* }
+    };
+
+    (
+        @internal_per_name 8, le
+
+        $(#[$attr:meta])*
+        $vis:vis static $symb:ident<$name:literal, $type:expr, $kind:ty> = $desc:expr;
+
+        $($next:tt)*
+    ) => {
+        #[link_section = concat!(".note.", $name)]
+        $(#[$attr])*
+        #[used]
+        $vis static $symb: $crate::Note8<$kind, {$name.len() + 1}> = $crate::Note8::new_le($name, $type, $desc);
+
+        noted! { @internal_per_name 8, le $($next)* }
+    };
+
+    (
+        @internal_per_name 8, be
+
+        $(#[$attr:meta])*
+        $vis:vis static $symb:ident<$name:literal, $type:expr, $kind:ty> = $desc:expr;
+
+        $($next:tt)*
+    ) => {
+        #[link_section = concat!(".note.", $name)]
+        $(#[$attr])*
+        #[used]
+        $vis static $symb: $crate::Note8<$kind, {$name.len() + 1}> = $crate::Note8::new_be($name, $type, $desc);
+
+        noted! { @internal_per_name 8, be $($next)* }
+    };
+
+    (section_per_name; align = $align:tt; endian = $endian:tt; $($next:tt)+) => {
+        noted! { @internal_per_name $align, $endian $($next)+ }
+    };
+    (section_per_name; align = $align:tt; $($next:tt)+) => {
+        noted! { @internal_per_name $align, native $($next)+ }
+    };
+    (section_per_name; endian = $endian:tt; $($next:tt)+) => {
+        noted! { @internal_per_name 4, $endian $($next)+ }
+    };
+    (section_per_name; $($next:tt)+) => {
+        noted! { @internal_per_name 4, native $($next)+ }
+    };
+
+    (section = $section:literal; align = $align:tt; endian = $endian:tt; $($next:tt)+) => {
+        noted! { @internal $section, $align, $endian $($next)+ }
+    };
+    (section = $section:literal; align = $align:tt; $($next:tt)+) => {
+        noted! { @internal $section, $align, native $($next)+ }
+    };
+    (section = $section:literal; endian = $endian:tt; $($next:tt)+) => {
+        noted! { @internal $section, 4, $endian $($next)+ }
+    };
     (section = $section:literal; $($next:tt)+) => {
-        noted! { @internal $section $($next)+ }
+        noted! { @internal $section, 4, native $($next)+ }
+    };
+
+    (align = $align:tt; endian = $endian:tt; $($next:tt)+) => {
+        noted! { @internal ".note", $align, $endian $($next)+ }
+    };
+    (align = $align:tt; $($next:tt)+) => {
+        noted! { @internal ".note", $align, native $($next)+ }
+    };
+    (endian = $endian:tt; $($next:tt)+) => {
+        noted! { @internal ".note", 4, $endian $($next)+ }
     };
 
     ($($next:tt)+) => {
-        noted! { @internal ".note" $($next)+ }
+        noted! { @internal ".note", 4, native $($next)+ }
     };
 }